@@ -0,0 +1,66 @@
+use js_sys::{BigUint64Array, Uint8Array};
+use wasm_bindgen::prelude::*;
+
+/// Written in place of a real bit index when a word is zero, so a batch
+/// scan never has to error out partway through a tick bitmap.
+const ZERO_SENTINEL: u8 = 255;
+
+fn msb_u64(value: u64) -> u8 {
+    if value == 0 {
+        ZERO_SENTINEL
+    } else {
+        63 - value.leading_zeros() as u8
+    }
+}
+
+fn lsb_u64(value: u64) -> u8 {
+    if value == 0 {
+        ZERO_SENTINEL
+    } else {
+        value.trailing_zeros() as u8
+    }
+}
+
+/// Computes the most significant bit index of each packed 64-bit word in
+/// `words` in a single boundary crossing, instead of one wrapper allocation
+/// and one JS call per word. Zero entries are written as the sentinel
+/// `255` rather than erroring, since scanning a tick bitmap for the next
+/// initialized tick routinely walks over empty words.
+#[wasm_bindgen]
+pub fn most_significant_bit_batch(words: &BigUint64Array) -> Uint8Array {
+    let len = words.length();
+    let out = Uint8Array::new_with_length(len);
+    for i in 0..len {
+        out.set_index(i, msb_u64(words.get_index(i)));
+    }
+    out
+}
+
+/// Like [`most_significant_bit_batch`], but for the least significant bit
+/// of each word.
+#[wasm_bindgen]
+pub fn least_significant_bit_batch(words: &BigUint64Array) -> Uint8Array {
+    let len = words.length();
+    let out = Uint8Array::new_with_length(len);
+    for i in 0..len {
+        out.set_index(i, lsb_u64(words.get_index(i)));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_msb_u64() {
+        assert_eq!(msb_u64(128), 7);
+        assert_eq!(msb_u64(0), ZERO_SENTINEL);
+    }
+
+    #[test]
+    fn test_lsb_u64() {
+        assert_eq!(lsb_u64(16), 4);
+        assert_eq!(lsb_u64(0), ZERO_SENTINEL);
+    }
+}
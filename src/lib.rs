@@ -1,27 +1,28 @@
 use wasm_bindgen::prelude::*;
-use serde::{Deserialize, Serialize};
-use serde_wasm_bindgen::{from_value, to_value};
 
-/// Serde ile `u128` serileştirilmesi ve deseralize edilmesi için yapı.
-/// WebAssembly'de `u128`'i işler hale getirmek için kullanılır.
-#[derive(Serialize, Deserialize)]
-pub struct U128Wrapper {
-    value: u128,
-}
+mod batch;
+mod mul_div;
+mod signed;
+mod tick_math;
+mod u256;
+pub use batch::{least_significant_bit_batch, most_significant_bit_batch};
+pub use mul_div::{mul_div, mul_div_rounding_up};
+pub use signed::{
+    abs, least_significant_bit_signed, most_significant_bit_signed, sign_bit, to_twos_complement,
+};
+pub use tick_math::{get_sqrt_ratio_at_tick, get_tick_at_sqrt_ratio, MAX_TICK, MIN_TICK};
+pub use u256::U256;
 
 /// Computes the index of the most significant bit of the u128 number.
 /// The least significant bit is at index 0, and the most significant bit is at index 127.
 ///
 /// # Arguments
-/// * `input` - Serde ile serileştirilen u128 değeri.
+/// * `value` - The u128 value, passed from JS as a native `BigInt`.
 ///
 /// # Returns
 /// The index of the most significant bit.
 #[wasm_bindgen]
-pub fn most_significant_bit(input: &JsValue) -> Result<JsValue, JsValue> {
-    let wrapper: U128Wrapper = from_value(input.clone()).map_err(|e| JsValue::from_str(&format!("Invalid input: {}", e)))?;
-    let value = wrapper.value;
-
+pub fn most_significant_bit(value: u128) -> Result<u8, JsValue> {
     if value == 0 {
         return Err(JsValue::from_str("Input must be greater than 0"));
     }
@@ -57,22 +58,19 @@ pub fn most_significant_bit(input: &JsValue) -> Result<JsValue, JsValue> {
         r += 1;
     }
 
-    to_value(&r).map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
+    Ok(r)
 }
 
 /// Computes the index of the least significant bit of the u128 number.
 /// The least significant bit is at index 0, and the most significant bit is at index 127.
 ///
 /// # Arguments
-/// * `input` - Serde ile serileştirilen u128 değeri.
+/// * `value` - The u128 value, passed from JS as a native `BigInt`.
 ///
 /// # Returns
 /// The index of the least significant bit.
 #[wasm_bindgen]
-pub fn least_significant_bit(input: &JsValue) -> Result<JsValue, JsValue> {
-    let wrapper: U128Wrapper = from_value(input.clone()).map_err(|e| JsValue::from_str(&format!("Invalid input: {}", e)))?;
-    let value = wrapper.value;
-
+pub fn least_significant_bit(value: u128) -> Result<u8, JsValue> {
     if value == 0 {
         return Err(JsValue::from_str("Input must be greater than 0"));
     }
@@ -80,7 +78,7 @@ pub fn least_significant_bit(input: &JsValue) -> Result<JsValue, JsValue> {
     let mut x = value;
     let mut r: u8 = 127;
 
-    if x & 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF > 0 {
+    if x & 0xFFFFFFFFFFFFFFFF > 0 {
         r -= 64;
     } else {
         x >>= 64;
@@ -114,33 +112,34 @@ pub fn least_significant_bit(input: &JsValue) -> Result<JsValue, JsValue> {
         r -= 1;
     }
 
-    to_value(&r).map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
+    Ok(r)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use serde_wasm_bindgen::to_value;
 
     #[test]
     fn test_most_significant_bit() {
-        let input = U128Wrapper { value: 128 };
-        let input_js = to_value(&input).unwrap();
-        assert_eq!(most_significant_bit(&input_js).unwrap(), JsValue::from(7));
+        assert_eq!(most_significant_bit(128).unwrap(), 7);
     }
 
     #[test]
     fn test_least_significant_bit() {
-        let input = U128Wrapper { value: 16 };
-        let input_js = to_value(&input).unwrap();
-        assert_eq!(least_significant_bit(&input_js).unwrap(), JsValue::from(4));
+        assert_eq!(least_significant_bit(16).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_least_significant_bit_above_64() {
+        // Only bit 100 set: the low 64 bits are all zero, so the LSB scan
+        // must actually cross into the high word instead of stopping early.
+        assert_eq!(least_significant_bit(1 << 100).unwrap(), 100);
+        assert_eq!(least_significant_bit(1 << 64).unwrap(), 64);
     }
 
     #[test]
     fn test_invalid_input() {
-        let input = U128Wrapper { value: 0 };
-        let input_js = to_value(&input).unwrap();
-        assert!(most_significant_bit(&input_js).is_err());
-        assert!(least_significant_bit(&input_js).is_err());
+        assert!(most_significant_bit(0).is_err());
+        assert!(least_significant_bit(0).is_err());
     }
 }
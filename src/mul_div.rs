@@ -0,0 +1,121 @@
+use crate::u256::U256;
+use wasm_bindgen::prelude::*;
+
+fn parse(label: &str, value: &str) -> Result<U256, JsValue> {
+    U256::from_hex_str(value).map_err(|e| JsValue::from_str(&format!("invalid {label}: {e}")))
+}
+
+/// `floor(a * b / denominator)` with a full 512-bit intermediate product,
+/// following Remco Bloemen's `mulDiv` (as used by Uniswap's `FullMath`):
+/// factor the power-of-two part of `denominator` out of the remainder,
+/// then finish with the modular inverse of the odd remainder mod `2^256`
+/// computed via Newton-Raphson, instead of a general 512-bit division.
+fn mul_div_raw(a: U256, b: U256, denominator: U256) -> Result<U256, String> {
+    if denominator.is_zero() {
+        return Err("denominator must be nonzero".to_string());
+    }
+
+    let (prod1, prod0) = a.mul_full(b);
+
+    if prod1.is_zero() {
+        return Ok(U256::divmod_wide(U256::ZERO, prod0, denominator).0);
+    }
+
+    if denominator <= prod1 {
+        return Err("result overflows u256".to_string());
+    }
+
+    let r = a.mulmod(b, denominator);
+    let prod1 = if r > prod0 { prod1 - U256::from_u128(1) } else { prod1 };
+    let prod0 = prod0 - r;
+
+    let twos = denominator.lowest_set_bit();
+    let shift = twos.trailing_zeros();
+    let denominator = denominator >> shift;
+    let mut prod0 = prod0 >> shift;
+
+    let twos_inv = U256::divmod_wide(U256::ZERO, twos.wrapping_neg(), twos).0 + U256::from_u128(1);
+    prod0 = prod0 | (prod1 * twos_inv);
+
+    let mut inv = (denominator * U256::from_u128(3)) ^ U256::from_u128(2);
+    for _ in 0..6 {
+        let t = U256::from_u128(2) - (denominator * inv);
+        inv = inv * t;
+    }
+
+    Ok(prod0 * inv)
+}
+
+/// `floor(a * b / denominator)`, computed with a full 512-bit intermediate
+/// product so it stays exact even when `a * b` overflows 256 bits.
+///
+/// `a`, `b`, and `denominator` are `0x`-prefixed 256-bit hex strings (see
+/// [`crate::tick_math::get_sqrt_ratio_at_tick`] for the same convention),
+/// kept so callers can pass the same string representation through both
+/// functions without an extra `U256` round trip.
+#[wasm_bindgen]
+pub fn mul_div(a: &str, b: &str, denominator: &str) -> Result<String, JsValue> {
+    let result = mul_div_raw(parse("a", a)?, parse("b", b)?, parse("denominator", denominator)?)
+        .map_err(|e| JsValue::from_str(&e))?;
+    Ok(result.to_hex())
+}
+
+/// Like [`mul_div`], but rounds the result up instead of truncating when
+/// `a * b` is not evenly divisible by `denominator`.
+#[wasm_bindgen]
+pub fn mul_div_rounding_up(a: &str, b: &str, denominator: &str) -> Result<String, JsValue> {
+    let a = parse("a", a)?;
+    let b = parse("b", b)?;
+    let denominator = parse("denominator", denominator)?;
+    let result = mul_div_raw(a, b, denominator).map_err(|e| JsValue::from_str(&e))?;
+    let remainder = a.mulmod(b, denominator);
+    let result = if remainder.is_zero() { result } else { result + U256::from_u128(1) };
+    Ok(result.to_hex())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(value: u128) -> String {
+        U256::from_u128(value).to_hex()
+    }
+
+    #[test]
+    fn test_mul_div_basic() {
+        assert_eq!(mul_div(&hex(10), &hex(3), &hex(2)).unwrap(), hex(15));
+    }
+
+    #[test]
+    fn test_mul_div_truncates() {
+        assert_eq!(mul_div(&hex(10), &hex(3), &hex(4)).unwrap(), hex(7));
+    }
+
+    #[test]
+    fn test_mul_div_rounding_up() {
+        assert_eq!(mul_div_rounding_up(&hex(10), &hex(3), &hex(4)).unwrap(), hex(8));
+        // Evenly divisible: rounding up must not add a spurious +1.
+        assert_eq!(mul_div_rounding_up(&hex(10), &hex(3), &hex(2)).unwrap(), hex(15));
+    }
+
+    #[test]
+    fn test_mul_div_zero_denominator_errors() {
+        assert!(mul_div(&hex(10), &hex(3), &hex(0)).is_err());
+    }
+
+    #[test]
+    fn test_mul_div_overflow_errors() {
+        let max = U256::MAX.to_hex();
+        assert!(mul_div(&max, &max, &hex(1)).is_err());
+    }
+
+    #[test]
+    fn test_mul_div_full_precision_product() {
+        // `a * b` overflows 256 bits on its own, but dividing back by `a`
+        // recovers `b` exactly only if the full 512-bit product was used.
+        let a = U256::MAX.to_hex();
+        let b = hex(2);
+        let denominator = a.clone();
+        assert_eq!(mul_div(&a, &b, &denominator).unwrap(), hex(2));
+    }
+}
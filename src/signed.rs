@@ -0,0 +1,165 @@
+use wasm_bindgen::prelude::*;
+
+/// Absolute value of a signed 128-bit integer, widened to `u128` so that
+/// `i128::MIN` (whose magnitude doesn't fit back in `i128`) is representable.
+#[wasm_bindgen]
+pub fn abs(value: i128) -> u128 {
+    value.unsigned_abs()
+}
+
+/// Two's-complement bit pattern of `value`, i.e. its raw `u128` representation.
+#[wasm_bindgen]
+pub fn to_twos_complement(value: i128) -> u128 {
+    value as u128
+}
+
+/// Whether `value` is negative.
+#[wasm_bindgen]
+pub fn sign_bit(value: i128) -> bool {
+    value < 0
+}
+
+/// Computes the index of the most significant bit of `value`'s magnitude.
+/// Negative inputs are reduced to their absolute value before scanning, and
+/// zero is rejected, matching [`crate::most_significant_bit`].
+///
+/// # Arguments
+/// * `value` - The i128 value, passed from JS as a native `BigInt`.
+#[wasm_bindgen]
+pub fn most_significant_bit_signed(value: i128) -> Result<u8, JsValue> {
+    let magnitude = abs(value);
+    if magnitude == 0 {
+        return Err(JsValue::from_str("Input must be non-zero"));
+    }
+
+    let mut x = magnitude;
+    let mut r: u8 = 0;
+
+    if x >= 0x10000000000000000 {
+        x >>= 64;
+        r += 64;
+    }
+    if x >= 0x100000000 {
+        x >>= 32;
+        r += 32;
+    }
+    if x >= 0x10000 {
+        x >>= 16;
+        r += 16;
+    }
+    if x >= 0x100 {
+        x >>= 8;
+        r += 8;
+    }
+    if x >= 0x10 {
+        x >>= 4;
+        r += 4;
+    }
+    if x >= 0x4 {
+        x >>= 2;
+        r += 2;
+    }
+    if x >= 0x2 {
+        r += 1;
+    }
+
+    Ok(r)
+}
+
+/// Computes the index of the least significant bit of `value`'s magnitude.
+/// Negative inputs are reduced to their absolute value before scanning, and
+/// zero is rejected, matching [`crate::least_significant_bit`].
+///
+/// # Arguments
+/// * `value` - The i128 value, passed from JS as a native `BigInt`.
+#[wasm_bindgen]
+pub fn least_significant_bit_signed(value: i128) -> Result<u8, JsValue> {
+    let magnitude = abs(value);
+    if magnitude == 0 {
+        return Err(JsValue::from_str("Input must be non-zero"));
+    }
+
+    let mut x = magnitude;
+    let mut r: u8 = 127;
+
+    if x & 0xFFFFFFFFFFFFFFFF > 0 {
+        r -= 64;
+    } else {
+        x >>= 64;
+    }
+    if x & 0xFFFFFFFF > 0 {
+        r -= 32;
+    } else {
+        x >>= 32;
+    }
+    if x & 0xFFFF > 0 {
+        r -= 16;
+    } else {
+        x >>= 16;
+    }
+    if x & 0xFF > 0 {
+        r -= 8;
+    } else {
+        x >>= 8;
+    }
+    if x & 0xF > 0 {
+        r -= 4;
+    } else {
+        x >>= 4;
+    }
+    if x & 0x3 > 0 {
+        r -= 2;
+    } else {
+        x >>= 2;
+    }
+    if x & 0x1 > 0 {
+        r -= 1;
+    }
+
+    Ok(r)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_abs_and_twos_complement() {
+        assert_eq!(abs(-5), 5);
+        assert_eq!(abs(5), 5);
+        assert_eq!(to_twos_complement(-1), u128::MAX);
+    }
+
+    #[test]
+    fn test_sign_bit() {
+        assert!(sign_bit(-1));
+        assert!(!sign_bit(1));
+        assert!(!sign_bit(0));
+    }
+
+    #[test]
+    fn test_most_significant_bit_signed() {
+        assert_eq!(most_significant_bit_signed(-128).unwrap(), 7);
+        assert_eq!(most_significant_bit_signed(128).unwrap(), 7);
+    }
+
+    #[test]
+    fn test_least_significant_bit_signed() {
+        assert_eq!(least_significant_bit_signed(-16).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_least_significant_bit_signed_above_64() {
+        // Only bit 100 set: the low 64 bits are all zero, so the LSB scan
+        // must actually cross into the high word instead of stopping early.
+        let value: i128 = 1 << 100;
+        assert_eq!(least_significant_bit_signed(value).unwrap(), 100);
+        assert_eq!(least_significant_bit_signed(-value).unwrap(), 100);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(most_significant_bit_signed(0).is_err());
+        assert!(least_significant_bit_signed(0).is_err());
+    }
+}
@@ -0,0 +1,192 @@
+use crate::u256::U256;
+use wasm_bindgen::prelude::*;
+
+/// Smallest tick handled by [`get_sqrt_ratio_at_tick`] / [`get_tick_at_sqrt_ratio`].
+pub const MIN_TICK: i32 = -887272;
+/// Largest tick handled by [`get_sqrt_ratio_at_tick`] / [`get_tick_at_sqrt_ratio`].
+pub const MAX_TICK: i32 = 887272;
+
+/// Widening `u128 * u128 -> (hi, lo)` 256-bit product, used by the bit
+/// refinement loop in [`get_tick_at_sqrt_ratio`]. This is a narrower
+/// operation than [`crate::u256::U256::mul_full`] (128x128 rather than
+/// 256x256), so it stays local rather than living on `U256`.
+fn full_mul_u128(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let a_lo = a & MASK;
+    let a_hi = a >> 64;
+    let b_lo = b & MASK;
+    let b_hi = b >> 64;
+
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+
+    let (mid, carry1) = hi_lo.overflowing_add(lo_hi);
+    let (mid, carry2) = mid.overflowing_add(lo_lo >> 64);
+    let carry = carry1 as u128 + carry2 as u128;
+
+    let lo = (lo_lo & MASK) | (mid << 64);
+    let hi = hi_hi + (mid >> 64) + (carry << 64);
+    (hi, lo)
+}
+
+/// Index of the highest set bit of `value`, or 0 for a zero input. Mirrors
+/// [`crate::most_significant_bit`] but without the zero-rejection, since
+/// this is reused internally on values that are allowed to be zero.
+fn msb_u256(value: U256) -> u16 {
+    value.most_significant_bit_index().map(|b| b as u16).unwrap_or(0)
+}
+
+const MAGIC_MULTIPLIERS: [u128; 19] = [
+    0xfff97272373d413259a46990580e213a,
+    0xfff2e50f5f656932ef12357cf3c7fdcc,
+    0xffe5caca7e10e4e61c3624eaa0941cd0,
+    0xffcb9843d60f6159c9db58835c926644,
+    0xff973b41fa98c081472e6896dfb254c0,
+    0xff2ea16466c96a3843ec78b326b52861,
+    0xfe5dee046a99a2a811c461f1969c3053,
+    0xfcbe86c7900a88aedcffc83b479aa3a4,
+    0xf987a7253ac413176f2b074cf7815e54,
+    0xf3392b0822b70005940c7a398e4b70f3,
+    0xe7159475a2c29b7443b29c7fa6e889d9,
+    0xd097f3bdfd2022b8845ad8f792aa5825,
+    0xa9f746462d870fdf8a65dc1f90e061e5,
+    0x70d869a156d2a1b890bb3df62baf32f7,
+    0x31be135f97d08fd981231505542fcfa6,
+    0x09aa508b5b7a84e1c677de54f3e99bc9,
+    0x005d6af8dedb81196699c329225ee604,
+    0x0002216e584f5fa1ea926041bedfe98,
+    0x00000048a170391f7dc42444e8fa2,
+];
+
+fn sqrt_ratio_for_abs_tick(abs_tick: u32) -> U256 {
+    let mut ratio = if abs_tick & 0x1 != 0 {
+        U256::from_u128(0xfffcb933bd6fad37aa2d162d1a594001)
+    } else {
+        U256::from_limbs([0, 0, 1, 0])
+    };
+    for (i, &multiplier) in MAGIC_MULTIPLIERS.iter().enumerate() {
+        if abs_tick & (0x2 << i) != 0 {
+            ratio = ratio.mul_u128_shift128(multiplier);
+        }
+    }
+    ratio
+}
+
+/// Returns the Q64.96 sqrt price for `tick`, accumulating the precomputed
+/// magic multipliers for each set bit of `abs(tick)`.
+///
+/// The result is returned as a `0x`-prefixed, zero-padded 64-digit hex
+/// string (the same convention [`crate::mul_div::mul_div`] uses) rather
+/// than a [`U256`] directly, so callers don't need to round-trip through
+/// the wasm boundary twice to get a displayable price.
+#[wasm_bindgen]
+pub fn get_sqrt_ratio_at_tick(tick: i32) -> Result<String, JsValue> {
+    if !(MIN_TICK..=MAX_TICK).contains(&tick) {
+        return Err(JsValue::from_str("tick out of range"));
+    }
+
+    let abs_tick = tick.unsigned_abs();
+    let mut ratio = sqrt_ratio_for_abs_tick(abs_tick);
+
+    if tick > 0 {
+        // `ratio` is nonzero here (every multiplier is nonzero), which
+        // `U256::MAX.divmod` requires of its divisor.
+        ratio = U256::MAX.divmod(ratio).0;
+    }
+
+    // Fold the Q128.128 ratio down to Q64.96, rounding up on truncation.
+    let sqrt_price_x96 = ratio >> 32;
+    let remainder = ratio - (sqrt_price_x96 << 32);
+    let sqrt_price_x96 =
+        if remainder.is_zero() { sqrt_price_x96 } else { sqrt_price_x96 + U256::from_u128(1) };
+
+    Ok(sqrt_price_x96.to_hex())
+}
+
+/// Returns the greatest tick whose `get_sqrt_ratio_at_tick` result is less
+/// than or equal to `sqrt_price_x96`, which must be a `0x`-prefixed hex
+/// string encoding a Q64.96 sqrt price (see [`get_sqrt_ratio_at_tick`]).
+#[wasm_bindgen]
+pub fn get_tick_at_sqrt_ratio(sqrt_price_x96: &str) -> Result<i32, JsValue> {
+    let price = U256::from_hex_str(sqrt_price_x96).map_err(|e| JsValue::from_str(&e))?;
+    let ratio = price << 32;
+
+    let msb = msb_u256(ratio);
+    let shifted = if msb >= 128 { ratio >> (msb - 127) as u32 } else { ratio << (127 - msb) as u32 };
+    let mut r = shifted.low_u128();
+
+    let mut log_2 = (msb as i128 - 128) << 64;
+
+    for i in 0..14u32 {
+        let (hi, lo) = full_mul_u128(r, r);
+        let carry_bit = hi >> 127;
+        let double_hi = hi << 1;
+        let (sum, carry2) = double_hi.overflowing_add(lo >> 127);
+        let f = carry_bit + carry2 as u128;
+        r = if f == 0 { sum } else { (f << (128 - f)) | (sum >> f) };
+        log_2 |= (f as i128) << (63 - i);
+    }
+
+    let log_sqrt10001 = U256::mul_i128(log_2, 255738958999603826347141);
+
+    let tick_low = (log_sqrt10001 - U256::from_u128(3402992956809132418596140100660247210)).high_i128();
+    let tick_hi = (log_sqrt10001 + U256::from_u128(291339464771989622907027621153398088495)).high_i128();
+
+    let tick_low = tick_low as i32;
+    let tick_hi = tick_hi as i32;
+
+    if tick_low == tick_hi {
+        Ok(tick_low)
+    } else {
+        let hi_price = U256::from_hex_str(&get_sqrt_ratio_at_tick(tick_hi)?).map_err(|e| JsValue::from_str(&e))?;
+        if hi_price <= price {
+            Ok(tick_hi)
+        } else {
+            Ok(tick_low)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `2^96`, the Q64.96 sqrt price at tick 0.
+    fn tick_zero_price() -> String {
+        format!("0x{:032x}{:032x}", 0u128, 1u128 << 96)
+    }
+
+    #[test]
+    fn test_tick_zero_is_two_to_the_96() {
+        assert_eq!(get_sqrt_ratio_at_tick(0).unwrap(), tick_zero_price());
+    }
+
+    #[test]
+    fn test_out_of_range_tick_errors() {
+        assert!(get_sqrt_ratio_at_tick(MIN_TICK - 1).is_err());
+        assert!(get_sqrt_ratio_at_tick(MAX_TICK + 1).is_err());
+    }
+
+    #[test]
+    fn test_price_increases_with_tick() {
+        // Equal-length, zero-padded hex strings compare numerically under
+        // plain string ordering, so this also pins the sign of the
+        // `tick > 0` inversion branch: a positive tick must yield a *larger*
+        // price than tick 0, not a smaller one.
+        let below = get_sqrt_ratio_at_tick(-1).unwrap();
+        let zero = tick_zero_price();
+        let above = get_sqrt_ratio_at_tick(1).unwrap();
+        assert!(below < zero);
+        assert!(zero < above);
+    }
+
+    #[test]
+    fn test_round_trip_sampled_ticks() {
+        for tick in [0, 1, -1, 2, -2, 100, -100, 500_000, -500_000, MIN_TICK, MAX_TICK] {
+            let price = get_sqrt_ratio_at_tick(tick).unwrap();
+            assert_eq!(get_tick_at_sqrt_ratio(&price).unwrap(), tick, "round trip failed for tick {tick}");
+        }
+    }
+}
@@ -0,0 +1,617 @@
+use std::cmp::Ordering;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Mul, Shl, Shr, Sub};
+use wasm_bindgen::prelude::*;
+
+/// Fixed 256-bit unsigned integer, modeled on rust-bitcoin's `Uint256`:
+/// four little-endian 64-bit limbs with arithmetic implemented limb-wise.
+/// TickMath and `mul_div` both need headroom beyond `u128` for Q64.96 /
+/// Q128.128 prices, so this is the crate's shared 256-bit numeric type.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct U256 {
+    limbs: [u64; 4],
+}
+
+impl U256 {
+    pub const ZERO: U256 = U256 { limbs: [0, 0, 0, 0] };
+    pub const MAX: U256 = U256 { limbs: [u64::MAX; 4] };
+
+    pub fn from_limbs(limbs: [u64; 4]) -> Self {
+        U256 { limbs }
+    }
+
+    pub fn limbs(&self) -> [u64; 4] {
+        self.limbs
+    }
+
+    pub fn from_u128(value: u128) -> Self {
+        U256 { limbs: [value as u64, (value >> 64) as u64, 0, 0] }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == U256::ZERO
+    }
+
+    pub fn from_hex_str(s: &str) -> Result<Self, String> {
+        let s = s.strip_prefix("0x").unwrap_or(s);
+        if s.is_empty() || s.len() > 64 {
+            return Err("hex value must fit in 256 bits".to_string());
+        }
+        let padded = format!("{:0>64}", s);
+        let mut limbs = [0u64; 4];
+        for i in 0..4 {
+            let chunk = &padded[i * 16..i * 16 + 16];
+            limbs[3 - i] = u64::from_str_radix(chunk, 16).map_err(|e| e.to_string())?;
+        }
+        Ok(U256 { limbs })
+    }
+
+    pub fn to_hex_string(&self) -> String {
+        format!("0x{:016x}{:016x}{:016x}{:016x}", self.limbs[3], self.limbs[2], self.limbs[1], self.limbs[0])
+    }
+
+    pub fn from_decimal_str(s: &str) -> Result<Self, String> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err("expected a decimal integer".to_string());
+        }
+        let ten = U256::from_u128(10);
+        let mut acc = U256::ZERO;
+        for b in s.bytes() {
+            let digit = U256::from_u128((b - b'0') as u128);
+            let (product, mul_overflow) = acc.overflowing_mul(ten);
+            let (sum, add_overflow) = product.overflowing_add(digit);
+            if mul_overflow || add_overflow {
+                return Err("value overflows u256".to_string());
+            }
+            acc = sum;
+        }
+        Ok(acc)
+    }
+
+    /// Widening `256x256 -> 512` multiply, returned as `(hi, lo)`.
+    pub(crate) fn mul_full(self, other: Self) -> (U256, U256) {
+        let mut acc = [0u128; 8];
+        for (i, &ai) in self.limbs.iter().enumerate() {
+            for (j, &bj) in other.limbs.iter().enumerate() {
+                let p = (ai as u128) * (bj as u128);
+                acc[i + j] += p & (u64::MAX as u128);
+                acc[i + j + 1] += p >> 64;
+            }
+        }
+        let mut out = [0u64; 8];
+        let mut carry: u128 = 0;
+        for (k, slot) in out.iter_mut().enumerate() {
+            let v = acc[k] + carry;
+            *slot = v as u64;
+            carry = v >> 64;
+        }
+        (U256 { limbs: [out[4], out[5], out[6], out[7]] }, U256 { limbs: [out[0], out[1], out[2], out[3]] })
+    }
+
+    pub fn overflowing_add(self, other: Self) -> (Self, bool) {
+        let mut out = [0u64; 4];
+        let mut carry = 0u64;
+        for (out_limb, (a, b)) in out.iter_mut().zip(self.limbs.iter().zip(other.limbs.iter())) {
+            let (s1, c1) = a.overflowing_add(*b);
+            let (s2, c2) = s1.overflowing_add(carry);
+            *out_limb = s2;
+            carry = (c1 as u64) + (c2 as u64);
+        }
+        (U256 { limbs: out }, carry != 0)
+    }
+
+    pub fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        let mut out = [0u64; 4];
+        let mut borrow = 0u64;
+        for (out_limb, (a, b)) in out.iter_mut().zip(self.limbs.iter().zip(other.limbs.iter())) {
+            let (d1, b1) = a.overflowing_sub(*b);
+            let (d2, b2) = d1.overflowing_sub(borrow);
+            *out_limb = d2;
+            borrow = (b1 as u64) + (b2 as u64);
+        }
+        (U256 { limbs: out }, borrow != 0)
+    }
+
+    /// Shifts left by exactly one bit, reporting whether a set bit was
+    /// shifted out of the top of the value. Used by the binary long
+    /// division routines below, where that bit still has to count against
+    /// the remainder even though it doesn't fit back in 256 bits.
+    fn shl1_overflowing(self) -> (Self, bool) {
+        let mut out = [0u64; 4];
+        let mut carry = 0u64;
+        for (out_limb, limb) in out.iter_mut().zip(self.limbs.iter()) {
+            let next_carry = limb >> 63;
+            *out_limb = (limb << 1) | carry;
+            carry = next_carry;
+        }
+        (U256 { limbs: out }, carry != 0)
+    }
+
+    pub fn overflowing_mul(self, other: Self) -> (Self, bool) {
+        let (hi, lo) = self.mul_full(other);
+        (lo, !hi.is_zero())
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        match self.overflowing_add(other) {
+            (v, false) => Some(v),
+            (_, true) => None,
+        }
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.overflowing_sub(other) {
+            (v, false) => Some(v),
+            (_, true) => None,
+        }
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        match self.overflowing_mul(other) {
+            (v, false) => Some(v),
+            (_, true) => None,
+        }
+    }
+
+    pub fn checked_shl(self, shift: u32) -> Option<Self> {
+        if shift >= 256 || !self.wrapping_shr(256 - shift).is_zero() {
+            return None;
+        }
+        Some(self.wrapping_shl(shift))
+    }
+
+    pub fn checked_shr(self, shift: u32) -> Option<Self> {
+        if shift >= 256 {
+            return None;
+        }
+        Some(self.wrapping_shr(shift))
+    }
+
+    pub fn wrapping_add(self, other: Self) -> Self {
+        self.overflowing_add(other).0
+    }
+
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        self.overflowing_sub(other).0
+    }
+
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        self.overflowing_mul(other).0
+    }
+
+    pub fn wrapping_shl(self, shift: u32) -> Self {
+        if shift == 0 {
+            return self;
+        }
+        if shift >= 256 {
+            return U256::ZERO;
+        }
+        let limb_shift = (shift / 64) as usize;
+        let bit_shift = shift % 64;
+        let mut out = [0u64; 4];
+        for i in (0..4).rev() {
+            if i < limb_shift {
+                continue;
+            }
+            let src = i - limb_shift;
+            let mut word = self.limbs[src] << bit_shift;
+            if bit_shift > 0 && src > 0 {
+                word |= self.limbs[src - 1] >> (64 - bit_shift);
+            }
+            out[i] = word;
+        }
+        U256 { limbs: out }
+    }
+
+    pub fn wrapping_shr(self, shift: u32) -> Self {
+        if shift == 0 {
+            return self;
+        }
+        if shift >= 256 {
+            return U256::ZERO;
+        }
+        let limb_shift = (shift / 64) as usize;
+        let bit_shift = shift % 64;
+        let mut out = [0u64; 4];
+        for (i, out_limb) in out.iter_mut().enumerate() {
+            let src = i + limb_shift;
+            if src >= 4 {
+                continue;
+            }
+            let mut word = self.limbs[src] >> bit_shift;
+            if bit_shift > 0 && src + 1 < 4 {
+                word |= self.limbs[src + 1] << (64 - bit_shift);
+            }
+            *out_limb = word;
+        }
+        U256 { limbs: out }
+    }
+
+    /// Low 128 bits of `self`, i.e. `self % 2^128`.
+    pub(crate) fn low_u128(self) -> u128 {
+        (self.limbs[0] as u128) | ((self.limbs[1] as u128) << 64)
+    }
+
+    /// Reinterprets bits `128..256` of `self` as a signed `i128`, i.e.
+    /// `(self >> 128) as i128` without an intermediate unsigned shift that
+    /// would lose the sign bit.
+    pub(crate) fn high_i128(self) -> i128 {
+        ((self.limbs[2] as u128) | ((self.limbs[3] as u128) << 64)) as i128
+    }
+
+    /// Builds the 256-bit two's-complement sign extension of an `i128`.
+    pub(crate) fn from_i128(value: i128) -> Self {
+        let bits = value as u128;
+        if value >= 0 {
+            U256::from_u128(bits)
+        } else {
+            U256 { limbs: [bits as u64, (bits >> 64) as u64, u64::MAX, u64::MAX] }
+        }
+    }
+
+    /// Signed `a * b`, kept as a 256-bit two's-complement value. Once both
+    /// inputs are sign-extended to 256 bits, two's-complement multiplication
+    /// is exact mod `2^256`, so this just multiplies the sign-extended
+    /// operands directly instead of juggling magnitudes.
+    pub(crate) fn mul_i128(a: i128, b: i128) -> Self {
+        U256::from_i128(a).wrapping_mul(U256::from_i128(b))
+    }
+
+    /// Two's-complement negation, i.e. `0 - self` mod `2^256`.
+    pub(crate) fn wrapping_neg(self) -> Self {
+        U256::ZERO.wrapping_sub(self)
+    }
+
+    /// `(self * multiplier) >> 128`, where `multiplier` is a Q128
+    /// fixed-point constant. This is the recurrence TickMath folds into its
+    /// running ratio for every set bit of the tick.
+    pub(crate) fn mul_u128_shift128(self, multiplier: u128) -> Self {
+        let (hi, lo) = self.mul_full(U256::from_u128(multiplier));
+        lo.wrapping_shr(128).wrapping_add(hi.wrapping_shl(128))
+    }
+
+    /// Isolates the lowest set bit of `self` as a power of two (zero if
+    /// `self` is zero).
+    pub(crate) fn lowest_set_bit(self) -> Self {
+        self & self.wrapping_neg()
+    }
+
+    /// Number of trailing zero bits, or 256 if `self` is zero.
+    pub(crate) fn trailing_zeros(self) -> u32 {
+        let mut total = 0u32;
+        for limb in self.limbs {
+            if limb != 0 {
+                return total + limb.trailing_zeros();
+            }
+            total += 64;
+        }
+        total
+    }
+
+    /// Schoolbook binary long division, returning `(quotient, remainder)`.
+    /// Callers must ensure `divisor` is nonzero.
+    pub(crate) fn divmod(self, divisor: Self) -> (Self, Self) {
+        let mut quotient = U256::ZERO;
+        let mut remainder = U256::ZERO;
+        for bit_index in (0..256).rev() {
+            let (mut shifted, overflow) = remainder.shl1_overflowing();
+            if (self.limbs[bit_index / 64] >> (bit_index % 64)) & 1 == 1 {
+                shifted.limbs[0] |= 1;
+            }
+            remainder = shifted;
+            if overflow || remainder >= divisor {
+                remainder = remainder.wrapping_sub(divisor);
+                quotient.limbs[bit_index / 64] |= 1u64 << (bit_index % 64);
+            }
+        }
+        (quotient, remainder)
+    }
+
+    /// Schoolbook binary long division of a (up to) 512-bit dividend,
+    /// passed as `(hi, lo)`, by a 256-bit `divisor`. The quotient is
+    /// truncated to 256 bits, which is safe for every call site in
+    /// [`crate::mul_div`]. Callers must ensure `divisor` is nonzero.
+    pub(crate) fn divmod_wide(hi: Self, lo: Self, divisor: Self) -> (Self, Self) {
+        let mut quotient = U256::ZERO;
+        let mut remainder = U256::ZERO;
+        for bit_index in (0..512).rev() {
+            let (mut shifted, overflow) = remainder.shl1_overflowing();
+            let bit = if bit_index >= 256 {
+                let i = bit_index - 256;
+                (hi.limbs[i / 64] >> (i % 64)) & 1 == 1
+            } else {
+                (lo.limbs[bit_index / 64] >> (bit_index % 64)) & 1 == 1
+            };
+            if bit {
+                shifted.limbs[0] |= 1;
+            }
+            remainder = shifted;
+            if overflow || remainder >= divisor {
+                remainder = remainder.wrapping_sub(divisor);
+                if bit_index < 256 {
+                    quotient.limbs[bit_index / 64] |= 1u64 << (bit_index % 64);
+                }
+            }
+        }
+        (quotient, remainder)
+    }
+
+    /// `a * b mod m`, via the 512-bit widening product and [`U256::divmod_wide`].
+    pub(crate) fn mulmod(self, other: Self, modulus: Self) -> Self {
+        let (hi, lo) = self.mul_full(other);
+        U256::divmod_wide(hi, lo, modulus).1
+    }
+
+    /// Index of the highest set bit (0..=255).
+    pub fn most_significant_bit_index(&self) -> Result<u8, String> {
+        for i in (0..4).rev() {
+            if self.limbs[i] != 0 {
+                return Ok(i as u8 * 64 + (63 - self.limbs[i].leading_zeros() as u8));
+            }
+        }
+        Err("Input must be greater than 0".to_string())
+    }
+
+    /// Index of the lowest set bit (0..=255).
+    pub fn least_significant_bit_index(&self) -> Result<u8, String> {
+        for i in 0..4 {
+            if self.limbs[i] != 0 {
+                return Ok(i as u8 * 64 + self.limbs[i].trailing_zeros() as u8);
+            }
+        }
+        Err("Input must be greater than 0".to_string())
+    }
+}
+
+impl Add for U256 {
+    type Output = U256;
+    fn add(self, rhs: Self) -> Self {
+        self.wrapping_add(rhs)
+    }
+}
+
+impl Sub for U256 {
+    type Output = U256;
+    fn sub(self, rhs: Self) -> Self {
+        self.wrapping_sub(rhs)
+    }
+}
+
+impl Mul for U256 {
+    type Output = U256;
+    fn mul(self, rhs: Self) -> Self {
+        self.wrapping_mul(rhs)
+    }
+}
+
+impl Shl<u32> for U256 {
+    type Output = U256;
+    fn shl(self, rhs: u32) -> Self {
+        self.wrapping_shl(rhs)
+    }
+}
+
+impl Shr<u32> for U256 {
+    type Output = U256;
+    fn shr(self, rhs: u32) -> Self {
+        self.wrapping_shr(rhs)
+    }
+}
+
+impl BitAnd for U256 {
+    type Output = U256;
+    fn bitand(self, rhs: Self) -> Self {
+        let mut out = [0u64; 4];
+        for (out_limb, (a, b)) in out.iter_mut().zip(self.limbs.iter().zip(rhs.limbs.iter())) {
+            *out_limb = a & b;
+        }
+        U256 { limbs: out }
+    }
+}
+
+impl BitOr for U256 {
+    type Output = U256;
+    fn bitor(self, rhs: Self) -> Self {
+        let mut out = [0u64; 4];
+        for (out_limb, (a, b)) in out.iter_mut().zip(self.limbs.iter().zip(rhs.limbs.iter())) {
+            *out_limb = a | b;
+        }
+        U256 { limbs: out }
+    }
+}
+
+impl BitXor for U256 {
+    type Output = U256;
+    fn bitxor(self, rhs: Self) -> Self {
+        let mut out = [0u64; 4];
+        for (out_limb, (a, b)) in out.iter_mut().zip(self.limbs.iter().zip(rhs.limbs.iter())) {
+            *out_limb = a ^ b;
+        }
+        U256 { limbs: out }
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        for i in (0..4).rev() {
+            match self.limbs[i].cmp(&other.limbs[i]) {
+                Ordering::Equal => continue,
+                other => return other,
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+impl std::fmt::Display for U256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_hex_string())
+    }
+}
+
+fn overflow_err(op: &str) -> JsValue {
+    JsValue::from_str(&format!("U256 {op} overflow"))
+}
+
+#[wasm_bindgen]
+impl U256 {
+    /// Parses a `0x`-prefixed (or bare) hex string into a `U256`.
+    #[wasm_bindgen(js_name = fromHex)]
+    pub fn from_hex(s: &str) -> Result<U256, JsValue> {
+        U256::from_hex_str(s).map_err(|e| JsValue::from_str(&e))
+    }
+
+    /// Builds a `U256` from a JS `BigInt`, via its base-10 string form.
+    #[wasm_bindgen(js_name = fromBigInt)]
+    pub fn from_bigint(value: js_sys::BigInt) -> Result<U256, JsValue> {
+        let digits: String = value
+            .to_string(10)
+            .map_err(|_| JsValue::from_str("invalid BigInt"))?
+            .into();
+        U256::from_decimal_str(&digits).map_err(|e| JsValue::from_str(&e))
+    }
+
+    /// Renders this value as a `0x`-prefixed, zero-padded 64-digit hex string.
+    #[wasm_bindgen(js_name = toHex)]
+    pub fn to_hex(&self) -> String {
+        self.to_hex_string()
+    }
+
+    #[wasm_bindgen(js_name = checkedAdd)]
+    pub fn js_checked_add(&self, other: &U256) -> Result<U256, JsValue> {
+        self.checked_add(*other).ok_or_else(|| overflow_err("add"))
+    }
+
+    #[wasm_bindgen(js_name = checkedSub)]
+    pub fn js_checked_sub(&self, other: &U256) -> Result<U256, JsValue> {
+        self.checked_sub(*other).ok_or_else(|| overflow_err("sub"))
+    }
+
+    #[wasm_bindgen(js_name = checkedMul)]
+    pub fn js_checked_mul(&self, other: &U256) -> Result<U256, JsValue> {
+        self.checked_mul(*other).ok_or_else(|| overflow_err("mul"))
+    }
+
+    #[wasm_bindgen(js_name = checkedShl)]
+    pub fn js_checked_shl(&self, shift: u32) -> Result<U256, JsValue> {
+        self.checked_shl(shift).ok_or_else(|| overflow_err("shl"))
+    }
+
+    #[wasm_bindgen(js_name = checkedShr)]
+    pub fn js_checked_shr(&self, shift: u32) -> Result<U256, JsValue> {
+        self.checked_shr(shift).ok_or_else(|| overflow_err("shr"))
+    }
+
+    #[wasm_bindgen(js_name = mostSignificantBit)]
+    pub fn most_significant_bit(&self) -> Result<u8, JsValue> {
+        self.most_significant_bit_index().map_err(|e| JsValue::from_str(&e))
+    }
+
+    #[wasm_bindgen(js_name = leastSignificantBit)]
+    pub fn least_significant_bit(&self) -> Result<u8, JsValue> {
+        self.least_significant_bit_index().map_err(|e| JsValue::from_str(&e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_round_trip() {
+        let value = U256::from_hex_str("0x1234abcd").unwrap();
+        assert_eq!(value.to_hex_string(), format!("0x{:056x}1234abcd", 0));
+        assert_eq!(U256::from_hex_str(&value.to_hex_string()).unwrap(), value);
+    }
+
+    #[test]
+    fn test_from_decimal_str() {
+        assert_eq!(U256::from_decimal_str("0").unwrap(), U256::ZERO);
+        assert_eq!(U256::from_decimal_str("340282366920938463463374607431768211456").unwrap(), U256::from_limbs([0, 0, 1, 0]));
+        assert!(U256::from_decimal_str("12x").is_err());
+    }
+
+    #[test]
+    fn test_add_sub_overflow() {
+        let (sum, overflow) = U256::MAX.overflowing_add(U256::from_u128(1));
+        assert!(overflow);
+        assert_eq!(sum, U256::ZERO);
+        assert_eq!(U256::ZERO.checked_sub(U256::from_u128(1)), None);
+        assert_eq!(U256::from_u128(5).wrapping_sub(U256::from_u128(3)), U256::from_u128(2));
+    }
+
+    #[test]
+    fn test_mul_overflow() {
+        assert_eq!(U256::from_u128(3).checked_mul(U256::from_u128(4)), Some(U256::from_u128(12)));
+        let two_to_128 = U256::from_limbs([0, 0, 1, 0]);
+        assert_eq!(two_to_128.checked_mul(two_to_128), None);
+    }
+
+    #[test]
+    fn test_shift() {
+        let one = U256::from_u128(1);
+        assert_eq!(one.wrapping_shl(128), U256::from_limbs([0, 0, 1, 0]));
+        assert_eq!(U256::from_limbs([0, 0, 1, 0]).wrapping_shr(128), one);
+        assert_eq!(U256::MAX.checked_shl(1), None);
+    }
+
+    #[test]
+    fn test_bitops() {
+        let a = U256::from_u128(0b1010);
+        let b = U256::from_u128(0b0110);
+        assert_eq!(a & b, U256::from_u128(0b0010));
+        assert_eq!(a | b, U256::from_u128(0b1110));
+        assert_eq!(a ^ b, U256::from_u128(0b1100));
+    }
+
+    #[test]
+    fn test_bit_index() {
+        let value = U256::from_limbs([0, 0, 1, 0]);
+        assert_eq!(value.most_significant_bit_index().unwrap(), 128);
+        assert_eq!(value.least_significant_bit_index().unwrap(), 128);
+        assert!(U256::ZERO.most_significant_bit_index().is_err());
+    }
+
+    #[test]
+    fn test_divmod() {
+        let (q, r) = U256::from_u128(100).divmod(U256::from_u128(7));
+        assert_eq!(q, U256::from_u128(14));
+        assert_eq!(r, U256::from_u128(2));
+
+        let (q_max, r_max) = U256::MAX.divmod(U256::from_u128(1));
+        assert_eq!(q_max, U256::MAX);
+        assert_eq!(r_max, U256::ZERO);
+    }
+
+    #[test]
+    fn test_mulmod() {
+        let a = U256::from_u128(u128::MAX);
+        let b = U256::from_u128(u128::MAX);
+        let m = U256::from_u128(1_000_000_007);
+        let (hi, lo) = a.mul_full(b);
+        let (_, expected) = U256::divmod_wide(hi, lo, m);
+        assert_eq!(a.mulmod(b, m), expected);
+    }
+
+    #[test]
+    fn test_signed_helpers() {
+        assert_eq!(U256::from_i128(-1), U256::MAX);
+        assert_eq!(U256::mul_i128(-2, 3), U256::from_i128(-6));
+        assert_eq!(U256::from_i128(-6).high_i128(), -1);
+        assert_eq!(U256::from_u128(5).wrapping_neg(), U256::from_i128(-5));
+    }
+
+    #[test]
+    fn test_lowest_set_bit_and_trailing_zeros() {
+        let value = U256::from_u128(0b1011000);
+        assert_eq!(value.lowest_set_bit(), U256::from_u128(0b1000));
+        assert_eq!(value.trailing_zeros(), 3);
+        assert_eq!(U256::ZERO.trailing_zeros(), 256);
+    }
+}